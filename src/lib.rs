@@ -10,10 +10,16 @@
 //! - Configurable scaling to control output size
 //! - Character aspect ratio correction for terminal display
 //! - Brightness-based character mapping for optimal visual representation
+//! - Optional true-color ANSI output that paints each glyph in its source pixel's color
+//! - Animated GIF playback, looping the decoded frames in the terminal
+//! - Fetching the source image from an HTTP(S) URL (behind the `fetch` feature)
+//! - Streaming raw `Gray8` video frames from stdin for use in an FFmpeg pipeline
+//! - Edge-aware rendering that swaps in directional glyphs along strong Sobel edges
+//! - Writing output to a file, including a colored HTML export
 //!
 //! # Example
 //!
-//! ```rust
+//! ```rust,no_run
 //! use aart::{Config, run};
 //! use std::path::PathBuf;
 //!
@@ -22,12 +28,26 @@
 //!     scale: 1.0,
 //!     char_width: 2,
 //!     char_height: 1,
+//!     color: false,
+//!     invert: false,
+//!     charset: aart::DEFAULT_CHARSET.to_string(),
+//!     loop_count: None,
+//!     edges: false,
+//!     edge_threshold: 100.0,
+//!     output: None,
 //! };
 //!
 //! run(&config).expect("Failed to convert image to ASCII art");
 //! ```
 use image::{GenericImageView, Pixel};
 
+/// The default brightness ramp, ordered from darkest to brightest glyph.
+///
+/// Shorter ramps such as `" .:-=+*#%@"` produce higher-contrast output since
+/// each glyph covers a wider slice of the luminance range.
+pub const DEFAULT_CHARSET: &str =
+    " .'`^\",:;Il!i><~+_-?][}{1)(|\\/tfjrxnuvczXYUJCLQ0OZmwqpdbkhao*#MW&8%B@$";
+
 /// Configuration parameters for ASCII art generation.
 pub struct Config {
     /// Path to the source image file that will be converted to ASCII art
@@ -38,20 +58,57 @@ pub struct Config {
     pub char_width: u32,
     /// Height of a single character in the terminal, used for aspect ratio correction
     pub char_height: u32,
+    /// Paint each glyph in its source pixel's true color using 24-bit ANSI escapes
+    pub color: bool,
+    /// Reverse the brightness ramp, for light-on-dark terminal themes
+    pub invert: bool,
+    /// Brightness ramp used by [`get_char`], ordered darkest to brightest.
+    /// Must be non-empty; see [`DEFAULT_CHARSET`] for the default ramp.
+    pub charset: String,
+    /// Number of times to replay an animated (multi-frame) input before stopping.
+    /// `None` means loop forever. Ignored for single-frame images.
+    pub loop_count: Option<u32>,
+    /// Pick structural glyphs (`|`, `-`, `/`, `\`) along strong edges instead of
+    /// purely brightness-based glyphs, for sharper line art
+    pub edges: bool,
+    /// Sobel gradient magnitude above which a pixel is considered an edge.
+    /// Only meaningful when `edges` is enabled
+    pub edge_threshold: f64,
+    /// Write the rendered art to this file instead of stdout. A `.html`
+    /// extension produces a colored HTML page via [`convert_image_to_html`];
+    /// any other extension writes plain text via [`convert_image_to_ascii`].
+    /// Animated (multi-frame) input and [`run_stream`] ignore this field and
+    /// always print to stdout.
+    pub output: Option<std::path::PathBuf>,
 }
 
 impl Config {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         image_path: std::path::PathBuf,
         scale: f64,
         char_width: u32,
         char_height: u32,
+        color: bool,
+        invert: bool,
+        charset: String,
+        loop_count: Option<u32>,
+        edges: bool,
+        edge_threshold: f64,
+        output: Option<std::path::PathBuf>,
     ) -> Config {
         Config {
             image_path,
             scale,
             char_width,
             char_height,
+            color,
+            invert,
+            charset,
+            loop_count,
+            edges,
+            edge_threshold,
+            output,
         }
     }
 }
@@ -63,6 +120,13 @@ impl Default for Config {
             scale: 1.0,
             char_width: 1,
             char_height: 1,
+            color: false,
+            invert: false,
+            charset: DEFAULT_CHARSET.to_string(),
+            loop_count: None,
+            edges: false,
+            edge_threshold: 100.0,
+            output: None,
         }
     }
 }
@@ -110,42 +174,50 @@ pub fn scale_image(image: image::DynamicImage, config: &Config) -> image::Dynami
 /// Converts a pixel's color values to a corresponding ASCII character based on brightness.
 ///
 /// This function takes an RGBA pixel and returns an ASCII character that represents
-/// its brightness level. It calculates the average of RGB channels (ignoring alpha)
-/// and maps it to a character from a predefined set of ASCII characters ranging from
-/// darkest (' ') to brightest ('$').
+/// its perceptual brightness. It computes luminance as a weighted sum of the RGB
+/// channels (ignoring alpha) using the standard `0.299*R + 0.587*G + 0.114*B`
+/// coefficients, then linearly maps the `0..=255` luminance range onto the ASCII
+/// ramp so that black maps to the first (darkest) glyph and white maps to the last
+/// (brightest) glyph.
 ///
 /// # Arguments
 ///
 /// * `pixel` - An RGBA pixel from the image
+/// * `charset` - The brightness ramp to pick a glyph from, ordered darkest to
+///   brightest (see [`DEFAULT_CHARSET`]). Must be non-empty.
+/// * `invert` - When `true`, reverses the ramp so dark pixels map to the brightest
+///   glyphs and vice versa, for light-on-dark terminal themes
 ///
 /// # Returns
 ///
-/// Returns a character from the ASCII set that corresponds to the pixel's brightness
+/// Returns a character from `charset` that corresponds to the pixel's brightness
+///
+/// # Panics
+///
+/// Panics if `charset` is empty. `Config` is public, so this guards direct
+/// library callers as well as the CLI's own `--chars` validation.
 ///
 /// # Example
 ///
 /// ```
 /// use image::Rgba;
-/// use aart::get_char;
+/// use aart::{get_char, DEFAULT_CHARSET};
 ///
 /// let pixel = Rgba([128, 128, 128, 255]); // Medium grey pixel
-/// let ascii_char = get_char(&pixel);
+/// let ascii_char = get_char(&pixel, DEFAULT_CHARSET, false);
 /// ```
-pub fn get_char(pixel: &image::Rgba<u8>) -> char {
-    static ASCII_CHARS: &str =
-        " .'`^\",:;Il!i><~+_-?][}{1)(|\\/tfjrxnuvczXYUJCLQ0OZmwqpdbkhao*#MW&8%B@$";
-    let avg_color = pixel
-        .channels()
-        .iter()
-        .take(3)
-        .map(|&c| c as f64)
-        .sum::<f64>()
-        / 3.0;
-
-    ASCII_CHARS
-        .chars()
-        .nth((avg_color as usize).rem_euclid(ASCII_CHARS.len()))
-        .unwrap()
+pub fn get_char(pixel: &image::Rgba<u8>, charset: &str, invert: bool) -> char {
+    assert!(!charset.is_empty(), "charset must not be empty");
+
+    let channels = pixel.channels();
+    let luminance =
+        0.299 * channels[0] as f64 + 0.587 * channels[1] as f64 + 0.114 * channels[2] as f64;
+
+    let last = charset.chars().count() - 1;
+    let idx = ((luminance / 255.0) * last as f64).round() as usize;
+    let idx = if invert { last - idx } else { idx };
+
+    charset.chars().nth(idx).unwrap()
 }
 
 /// Converts an image into ASCII art representation.
@@ -163,7 +235,14 @@ pub fn get_char(pixel: &image::Rgba<u8>) -> char {
 /// # Returns
 ///
 /// Returns a String containing the ASCII art representation of the image,
-/// with newline characters separating each row.
+/// with newline characters separating each row. When `color` is `true`,
+/// each glyph is wrapped in a 24-bit ANSI truecolor escape sequence derived
+/// from its source pixel, with a reset emitted at the end of every line.
+/// Callers decide `color` based on their actual output sink (e.g. whether
+/// stdout is a TTY); this function does not inspect global I/O state.
+/// When `config.edges` is set, pixels that sit on a strong Sobel edge are
+/// rendered with a directional glyph (see [`edge_char`]) instead of the
+/// brightness ramp.
 ///
 /// # Example
 ///
@@ -179,18 +258,24 @@ pub fn get_char(pixel: &image::Rgba<u8>) -> char {
 ///     ..Default::default()
 /// };
 ///
-/// let ascii_art = convert_image_to_ascii(img, &config);
+/// let ascii_art = convert_image_to_ascii(img, &config, false);
 /// println!("{}", ascii_art);
 /// ```
-pub fn convert_image_to_ascii(image: image::DynamicImage, config: &Config) -> String {
-    let image = scale_image(image, config);
-    let (width, height) = image.dimensions();
+pub fn convert_image_to_ascii(image: image::DynamicImage, config: &Config, color: bool) -> String {
+    let (width, height, cells) = rendered_cells(image, config);
     let mut ascii_image = String::new();
 
     for y in 0..height {
         for x in 0..width {
-            let pixel = image.get_pixel(x, y);
-            ascii_image.push(get_char(&pixel));
+            let (ch, [r, g, b]) = cells[(y * width + x) as usize];
+            if color {
+                ascii_image.push_str(&format!("\x1b[38;2;{r};{g};{b}m{ch}"));
+            } else {
+                ascii_image.push(ch);
+            }
+        }
+        if color {
+            ascii_image.push_str("\x1b[0m");
         }
         ascii_image.push('\n');
     }
@@ -198,6 +283,129 @@ pub fn convert_image_to_ascii(image: image::DynamicImage, config: &Config) -> St
     ascii_image
 }
 
+/// Scales `image` and picks a glyph and source color for every pixel,
+/// sharing the brightness/edge-detection logic between [`convert_image_to_ascii`]
+/// and [`convert_image_to_html`]. Returns `(width, height, cells)` where
+/// `cells` is row-major, one `(glyph, [r, g, b])` entry per pixel.
+fn rendered_cells(image: image::DynamicImage, config: &Config) -> (u32, u32, Vec<(char, [u8; 3])>) {
+    let image = scale_image(image, config);
+    let (width, height) = image.dimensions();
+    let gray = config.edges.then(|| image.to_luma8());
+    let mut cells = Vec::with_capacity((width * height) as usize);
+
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = image.get_pixel(x, y);
+            let ch = gray
+                .as_ref()
+                .and_then(|gray| edge_char(gray, x, y, config.edge_threshold))
+                .unwrap_or_else(|| get_char(&pixel, &config.charset, config.invert));
+            let [r, g, b, _] = pixel.0;
+            cells.push((ch, [r, g, b]));
+        }
+    }
+
+    (width, height, cells)
+}
+
+/// Converts an image into a standalone HTML document, with each glyph wrapped
+/// in a `<span style="color:#rrggbb">` colored from its source pixel. This
+/// produces a shareable, color ASCII-art page from the same brightness/edge
+/// pipeline as [`convert_image_to_ascii`].
+///
+/// # Example
+///
+/// ```
+/// use aart::{Config, convert_image_to_html};
+/// use image::DynamicImage;
+///
+/// let img = DynamicImage::new_rgb8(100, 100);
+/// let config = Config {
+///     scale: 1.0,
+///     char_width: 2,
+///     char_height: 1,
+///     ..Default::default()
+/// };
+///
+/// let html = convert_image_to_html(img, &config);
+/// ```
+pub fn convert_image_to_html(image: image::DynamicImage, config: &Config) -> String {
+    let (width, height, cells) = rendered_cells(image, config);
+    let mut body = String::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            let (ch, [r, g, b]) = cells[(y * width + x) as usize];
+            body.push_str(&format!(
+                "<span style=\"color:#{r:02x}{g:02x}{b:02x}\">{}</span>",
+                html_escape_char(ch)
+            ));
+        }
+        body.push('\n');
+    }
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head><meta charset=\"utf-8\"><title>ASCII Art</title></head>\n\
+         <body style=\"background:#000;\">\n\
+         <pre style=\"font-family: monospace; line-height: 1;\">\n\
+         {body}</pre>\n\
+         </body>\n\
+         </html>\n"
+    )
+}
+
+/// Escapes a single glyph for embedding in HTML text, preserving spaces so
+/// the monospace grid does not collapse.
+fn html_escape_char(ch: char) -> std::borrow::Cow<'static, str> {
+    match ch {
+        '&' => "&amp;".into(),
+        '<' => "&lt;".into(),
+        '>' => "&gt;".into(),
+        ' ' => "&nbsp;".into(),
+        other => other.to_string().into(),
+    }
+}
+
+/// Returns a directional glyph for the pixel at `(x, y)` in `gray` if it sits
+/// on a strong edge, or `None` if it is interior-flat (below `threshold`) or
+/// on the image border (no neighbors to compute a gradient from).
+///
+/// Horizontal and vertical Sobel gradients `Gx`/`Gy` are computed over the
+/// 3x3 neighborhood; pixels whose gradient magnitude `sqrt(Gx^2 + Gy^2)`
+/// exceeds `threshold` are classified by quantizing the edge angle
+/// `atan2(Gy, Gx)` into four 45-degree bins: ~0 degrees -> `-`, ~45 degrees -> `/`,
+/// ~90 degrees -> `|`, ~135 degrees -> `\`.
+pub fn edge_char(gray: &image::GrayImage, x: u32, y: u32, threshold: f64) -> Option<char> {
+    let (width, height) = gray.dimensions();
+    if x == 0 || y == 0 || x + 1 >= width || y + 1 >= height {
+        return None;
+    }
+
+    let p =
+        |dx: i32, dy: i32| gray.get_pixel((x as i32 + dx) as u32, (y as i32 + dy) as u32)[0] as f64;
+
+    let gx = -p(-1, -1) + p(1, -1) - 2.0 * p(-1, 0) + 2.0 * p(1, 0) - p(-1, 1) + p(1, 1);
+    let gy = -p(-1, -1) - 2.0 * p(0, -1) - p(1, -1) + p(-1, 1) + 2.0 * p(0, 1) + p(1, 1);
+    let magnitude = (gx * gx + gy * gy).sqrt();
+
+    if magnitude <= threshold {
+        return None;
+    }
+
+    let angle = gy.atan2(gx).to_degrees().rem_euclid(180.0);
+    Some(if !(22.5..157.5).contains(&angle) {
+        '-'
+    } else if angle < 67.5 {
+        '/'
+    } else if angle < 112.5 {
+        '|'
+    } else {
+        '\\'
+    })
+}
+
 /// Executes the main ASCII art conversion process.
 ///
 /// This function orchestrates the complete process of converting an image to ASCII art:
@@ -230,6 +438,13 @@ pub fn convert_image_to_ascii(image: image::DynamicImage, config: &Config) -> St
 ///     scale: 1.0,
 ///     char_width: 2,
 ///     char_height: 1,
+///     color: false,
+///     invert: false,
+///     charset: aart::DEFAULT_CHARSET.to_string(),
+///     loop_count: None,
+///     edges: false,
+///     edge_threshold: 100.0,
+///     output: None,
 /// };
 ///
 /// match run(&config) {
@@ -238,10 +453,169 @@ pub fn convert_image_to_ascii(image: image::DynamicImage, config: &Config) -> St
 /// }
 /// ```
 pub fn run(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
-    let image = image::open(&config.image_path)?;
-    let ascii_image = convert_image_to_ascii(image, config);
+    let bytes = load_bytes(&config.image_path)?;
 
-    println!("{}", ascii_image);
+    if image::guess_format(&bytes).ok() == Some(image::ImageFormat::Gif) {
+        let decoder = image::codecs::gif::GifDecoder::new(std::io::Cursor::new(&bytes))?;
+        let frames = image::AnimationDecoder::into_frames(decoder).collect_frames()?;
+        if frames.len() > 1 {
+            return run_animation(config, frames);
+        }
+    }
+
+    let image = image::load_from_memory(&bytes)?;
+
+    match &config.output {
+        Some(path) if is_html_path(path) => {
+            std::fs::write(path, convert_image_to_html(image, config))?;
+        }
+        Some(path) => {
+            // A file is not a terminal, so there is no TTY to auto-detect;
+            // honor `config.color` outright, same as `convert_image_to_html`
+            // always embeds color regardless of how stdout is connected.
+            std::fs::write(path, convert_image_to_ascii(image, config, config.color))?;
+        }
+        None => {
+            let color = config.color && std::io::IsTerminal::is_terminal(&std::io::stdout());
+            println!("{}", convert_image_to_ascii(image, config, color));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_html_path(path: &std::path::Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("html"))
+}
+
+/// Reads the raw bytes of `image_path`, transparently fetching it over HTTP(S)
+/// when it is a URL rather than a local path.
+fn load_bytes(image_path: &std::path::Path) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    match image_path.to_str() {
+        Some(s) if is_url(s) => fetch_bytes(s),
+        _ => Ok(std::fs::read(image_path)?),
+    }
+}
+
+fn is_url(s: &str) -> bool {
+    s.starts_with("http://") || s.starts_with("https://")
+}
+
+/// Downloads the bytes at `url`. Requires the crate to be built with the
+/// `fetch` feature; without it, every URL is rejected with an explanatory error.
+#[cfg(feature = "fetch")]
+fn fetch_bytes(url: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    use std::io::Read;
+
+    let response = ureq::get(url).call()?;
+    let mut bytes = Vec::new();
+    response.into_reader().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch_bytes(_url: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    Err("fetching images from URLs requires building aart with the `fetch` feature".into())
+}
+
+/// Plays back a decoded animation in the terminal.
+///
+/// Each frame is converted with [`convert_image_to_ascii`] and drawn in a loop,
+/// clearing the screen (`\x1b[2J\x1b[H`) and sleeping for the frame's own delay
+/// between draws. Playback repeats `config.loop_count` times, or forever when
+/// `loop_count` is `None`. A `loop_count` of `Some(0)` means "don't play" and
+/// returns immediately without drawing a single frame.
+fn run_animation(
+    config: &Config,
+    frames: Vec<image::Frame>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::Write;
+
+    if config.loop_count == Some(0) {
+        return Ok(());
+    }
+
+    let color = config.color && std::io::IsTerminal::is_terminal(&std::io::stdout());
+    let frames: Vec<(String, std::time::Duration)> = frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay_ms = numer.checked_div(denom).unwrap_or(0);
+            let image = image::DynamicImage::ImageRgba8(frame.into_buffer());
+            (
+                convert_image_to_ascii(image, config, color),
+                std::time::Duration::from_millis(delay_ms as u64),
+            )
+        })
+        .collect();
+
+    let mut stdout = std::io::stdout();
+    let mut played = 0u32;
+    loop {
+        for (ascii_frame, delay) in &frames {
+            print!("\x1b[2J\x1b[H{ascii_frame}");
+            stdout.flush()?;
+            std::thread::sleep(*delay);
+        }
+
+        played += 1;
+        if config.loop_count.is_some_and(|count| played >= count) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads raw `Gray8` frames from stdin and writes their ASCII conversion to
+/// stdout, one frame at a time, until EOF.
+///
+/// Each frame is exactly `width * height` bytes. The tool can sit in an
+/// FFmpeg pipeline this way:
+///
+/// ```text
+/// ffmpeg -i input.mp4 -f rawvideo -pix_fmt gray - | aart --stream -w 640 -H 480
+/// ```
+///
+/// `config.image_path`, `config.loop_count` and `config.output` are ignored
+/// (frames always go to stdout); every other field (scale, charset, color,
+/// invert, edges, ...) still applies to each streamed frame. A partial
+/// final read (fewer than `width * height` bytes before EOF) stops the
+/// stream instead of erroring, since that is simply how the pipe ends.
+pub fn run_stream(
+    config: &Config,
+    width: u32,
+    height: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{Read, Write};
+
+    let color = config.color && std::io::IsTerminal::is_terminal(&std::io::stdout());
+    let frame_size = width as usize * height as usize;
+    let mut stdin = std::io::BufReader::new(std::io::stdin());
+    let mut stdout = std::io::stdout();
+    let mut buf = vec![0u8; frame_size];
+
+    loop {
+        let mut read = 0;
+        while read < frame_size {
+            match stdin.read(&mut buf[read..])? {
+                0 => break,
+                n => read += n,
+            }
+        }
+        if read < frame_size {
+            break;
+        }
+
+        let frame = image::GrayImage::from_raw(width, height, buf.clone())
+            .ok_or("stdin frame does not match the given width/height")?;
+        let ascii_frame =
+            convert_image_to_ascii(image::DynamicImage::ImageLuma8(frame), config, color);
+        print!("{ascii_frame}");
+        stdout.flush()?;
+    }
 
     Ok(())
 }
@@ -252,38 +626,68 @@ mod tests {
     use image::Rgba;
 
     #[test]
-    fn get_char_returns_correct_char_on_black_pixel() {
+    fn get_char_returns_darkest_glyph_on_black_pixel() {
         let black_pixel = Rgba([0, 0, 0, 255]);
-        assert_eq!(get_char(&black_pixel), ' ');
+        assert_eq!(get_char(&black_pixel, DEFAULT_CHARSET, false), ' ');
     }
 
     #[test]
-    fn get_char_returns_correct_char_on_white_pixel() {
+    fn get_char_returns_brightest_glyph_on_white_pixel() {
         let white_pixel = Rgba([255, 255, 255, 255]);
-        assert_eq!(get_char(&white_pixel), 'L');
+        assert_eq!(get_char(&white_pixel, DEFAULT_CHARSET, false), '$');
     }
 
     #[test]
     fn get_char_returns_correct_char_on_grey_pixel() {
         let grey_pixel = Rgba([128, 128, 128, 255]);
-        let result = get_char(&grey_pixel);
-        assert_eq!(result, 'a');
+        let result = get_char(&grey_pixel, DEFAULT_CHARSET, false);
+        assert_eq!(result, 'n');
     }
 
     #[test]
-    fn get_char_returns_correct_char_on_equivalent_avg() {
-        let mixed_pixel = Rgba([50, 100, 150, 255]);
-        // Average is 100, should return consistent character
-        let result = get_char(&mixed_pixel);
-        let same_avg_pixel = Rgba([100, 100, 100, 255]);
-        assert_eq!(result, get_char(&same_avg_pixel));
+    fn get_char_weighs_green_brighter_than_blue() {
+        // Equal averages, but perceptual luminance weighs green far more than
+        // blue, so these must not map to the same glyph.
+        let green_pixel = Rgba([0, 255, 0, 255]);
+        let blue_pixel = Rgba([0, 0, 255, 255]);
+        assert_ne!(
+            get_char(&green_pixel, DEFAULT_CHARSET, false),
+            get_char(&blue_pixel, DEFAULT_CHARSET, false)
+        );
     }
 
     #[test]
     fn get_char_ignores_alpha_channel() {
         let pixel1 = Rgba([100, 100, 100, 255]);
         let pixel2 = Rgba([100, 100, 100, 0]);
-        assert_eq!(get_char(&pixel1), get_char(&pixel2));
+        assert_eq!(
+            get_char(&pixel1, DEFAULT_CHARSET, false),
+            get_char(&pixel2, DEFAULT_CHARSET, false)
+        );
+    }
+
+    #[test]
+    fn get_char_invert_reverses_the_ramp() {
+        let black_pixel = Rgba([0, 0, 0, 255]);
+        let white_pixel = Rgba([255, 255, 255, 255]);
+        assert_eq!(get_char(&black_pixel, DEFAULT_CHARSET, true), '$');
+        assert_eq!(get_char(&white_pixel, DEFAULT_CHARSET, true), ' ');
+    }
+
+    #[test]
+    fn get_char_uses_custom_charset() {
+        let black_pixel = Rgba([0, 0, 0, 255]);
+        let white_pixel = Rgba([255, 255, 255, 255]);
+        let charset = " .:-=+*#%@";
+        assert_eq!(get_char(&black_pixel, charset, false), ' ');
+        assert_eq!(get_char(&white_pixel, charset, false), '@');
+    }
+
+    #[test]
+    #[should_panic(expected = "charset must not be empty")]
+    fn get_char_panics_on_empty_charset() {
+        let pixel = Rgba([128, 128, 128, 255]);
+        get_char(&pixel, "", false);
     }
 
     use image::{DynamicImage, GenericImageView, ImageBuffer, Rgb};
@@ -320,4 +724,52 @@ mod tests {
         let scaled = scale_image(input, &config);
         assert_eq!(scaled.dimensions(), (200, 200));
     }
+
+    fn create_test_gray_image(width: u32, height: u32, left: u8, right: u8) -> image::GrayImage {
+        ImageBuffer::from_fn(width, height, |x, _| {
+            image::Luma([if x < width / 2 { left } else { right }])
+        })
+    }
+
+    #[test]
+    fn edge_char_detects_a_strong_brightness_step() {
+        let gray = create_test_gray_image(5, 5, 0, 255);
+        assert_eq!(edge_char(&gray, 2, 2, 50.0), Some('-'));
+    }
+
+    #[test]
+    fn edge_char_returns_none_on_a_flat_region() {
+        let gray = create_test_gray_image(5, 5, 128, 128);
+        assert_eq!(edge_char(&gray, 2, 2, 50.0), None);
+    }
+
+    #[test]
+    fn edge_char_returns_none_on_border_pixels() {
+        let gray = create_test_gray_image(5, 5, 0, 255);
+        assert_eq!(edge_char(&gray, 0, 0, 1.0), None);
+    }
+
+    #[test]
+    fn convert_image_to_html_wraps_glyphs_in_colored_spans() {
+        let input = create_test_image(2, 1);
+        let config = Config {
+            scale: 1.0,
+            char_width: 1,
+            char_height: 1,
+            ..Default::default()
+        };
+
+        let html = convert_image_to_html(input, &config);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<span style=\"color:#646464\">"));
+        assert!(html.contains("<pre"));
+    }
+
+    #[test]
+    fn convert_image_to_html_escapes_reserved_html_characters() {
+        assert_eq!(html_escape_char('<'), "&lt;");
+        assert_eq!(html_escape_char('&'), "&amp;");
+        assert_eq!(html_escape_char(' '), "&nbsp;");
+        assert_eq!(html_escape_char('a'), "a");
+    }
 }
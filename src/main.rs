@@ -4,10 +4,11 @@ use clap::Parser;
 #[command(version, about, long_about = None)]
 struct Args {
     #[arg(
-        value_enum,
-        help = "path to image file"
+        required_unless_present = "stream",
+        help = "path to image file, or an http(s):// URL when built with the `fetch` feature; \
+                omitted in --stream mode"
     )]
-    image_path: std::path::PathBuf,
+    image_path: Option<std::path::PathBuf>,
 
     #[arg(
         short = 's',
@@ -29,11 +30,84 @@ struct Args {
     #[arg(
         short = 'y',
         long,
-        default_value_t = 18, 
+        default_value_t = 18,
         value_parser = clap::value_parser!(u32).range(1..=256),
         help = "character_width"
     )]
     char_height: u32,
+
+    #[arg(
+        short = 'c',
+        long,
+        help = "paint output using the source image's true colors (ANSI truecolor)"
+    )]
+    color: bool,
+
+    #[arg(
+        long,
+        help = "reverse the brightness ramp, for light-on-dark terminal themes"
+    )]
+    invert: bool,
+
+    #[arg(
+        long = "chars",
+        default_value_t = aart::DEFAULT_CHARSET.to_string(),
+        help = "brightness ramp, ordered dark to light; shorter ramps give higher contrast"
+    )]
+    charset: String,
+
+    #[arg(
+        long = "loop",
+        help = "number of times to replay an animated GIF (default: loop forever)"
+    )]
+    loop_count: Option<u32>,
+
+    #[arg(
+        long,
+        requires_all = ["width", "height"],
+        help = "read raw Gray8 frames from stdin and stream ASCII frames to stdout, \
+                for piping from ffmpeg"
+    )]
+    stream: bool,
+
+    #[arg(
+        short = 'w',
+        long,
+        requires = "stream",
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "frame width in pixels, required with --stream"
+    )]
+    width: Option<u32>,
+
+    #[arg(
+        short = 'H',
+        long,
+        requires = "stream",
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "frame height in pixels, required with --stream"
+    )]
+    height: Option<u32>,
+
+    #[arg(
+        long,
+        help = "render directional glyphs (|, -, /, \\) along strong edges instead of brightness alone"
+    )]
+    edges: bool,
+
+    #[arg(
+        long,
+        default_value_t = 100.0,
+        help = "Sobel gradient magnitude above which a pixel is treated as an edge"
+    )]
+    edge_threshold: f64,
+
+    #[arg(
+        short = 'o',
+        long,
+        help = "write output to this file instead of stdout; a .html extension writes a colored HTML page \
+                (ignored by --stream and animated GIFs, which always print to stdout)"
+    )]
+    output: Option<std::path::PathBuf>,
 }
 
 fn main() {
@@ -42,15 +116,66 @@ fn main() {
         eprintln!("error: scale must be between 0.01 and 1.0");
         std::process::exit(1);
     }
+    if args.charset.is_empty() {
+        eprintln!("error: --chars must not be empty");
+        std::process::exit(1);
+    }
+
     let config = aart::Config::new(
-        args.image_path,
+        args.image_path.unwrap_or_default(),
         args.scale,
         args.char_width,
         args.char_height,
+        args.color,
+        args.invert,
+        args.charset,
+        args.loop_count,
+        args.edges,
+        args.edge_threshold,
+        args.output,
     );
 
-    if let Err(e) = aart::run(&config) {
+    let result = if args.stream {
+        aart::run_stream(&config, args.width.unwrap(), args.height.unwrap())
+    } else {
+        aart::run(&config)
+    };
+
+    if let Err(e) = result {
         eprintln!("error: {}", e);
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_definition_has_no_conflicting_flags() {
+        // Catches duplicate short/long names (e.g. a custom flag stealing
+        // clap's auto-generated -h/--help) that would otherwise only panic
+        // the moment the binary is actually run.
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn cli_help_can_still_be_rendered() {
+        let result = Args::command().try_get_matches_from(["aart", "--help"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cli_rejects_zero_stream_dimensions() {
+        // A zero width/height would make run_stream's frame_size 0, which
+        // spins forever reading nothing instead of erroring or streaming.
+        let result =
+            Args::command().try_get_matches_from(["aart", "--stream", "-w", "0", "-H", "480"]);
+        assert!(result.is_err());
+
+        let result =
+            Args::command().try_get_matches_from(["aart", "--stream", "-w", "640", "-H", "0"]);
+        assert!(result.is_err());
+    }
+}